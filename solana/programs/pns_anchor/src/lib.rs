@@ -1,6 +1,7 @@
 #![allow(unexpected_cfgs)]
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 declare_id!("EB6pbr3ZRnZv1bhgffQuuER5armxMRNauNWRabzuiaNj");
 
@@ -13,18 +14,31 @@ pub mod pns_anchor {
         ctx: Context<Initialize>,
         polygon_registry: [u8; 20],
         conflict_policy: ConflictPolicy,
+        grace_period_seconds: u64,
+        realm: Pubkey,
+        governing_token_mint: Pubkey,
+        required_signoffs: u8,
     ) -> Result<()> {
         let registry = &mut ctx.accounts.registry;
         registry.authority = ctx.accounts.authority.key();
         registry.polygon_registry = polygon_registry;
         registry.domain_count = 0;
         registry.conflict_policy = conflict_policy;
+        registry.grace_period_seconds = grace_period_seconds;
+        registry.realm = realm;
+        registry.governing_token_mint = governing_token_mint;
+        registry.required_signoffs = required_signoffs;
+        registry.signed_off_count = 0;
+        registry.quorum_generation = 1;
         registry.bump = ctx.bumps.registry;
         registry.version = REGISTRY_VERSION;
 
         msg!(
-            "Registry initialized with conflict policy {:?}",
-            conflict_policy
+            "Registry initialized with conflict policy {:?}, grace period {}s, realm {}, required signoffs {}",
+            conflict_policy,
+            grace_period_seconds,
+            realm,
+            required_signoffs
         );
         Ok(())
     }
@@ -60,6 +74,7 @@ pub mod pns_anchor {
         domain.nft_mint = None;
         domain.wrap_state = WrapState::None;
         domain.record_count = 0;
+        domain.lockup = None;
         domain.bump = ctx.bumps.domain_account;
 
         registry.domain_count = registry.domain_count.saturating_add(1);
@@ -115,6 +130,10 @@ pub mod pns_anchor {
             domain.owner == ctx.accounts.owner.key(),
             PnsError::Unauthorized
         );
+        require!(
+            !domain.is_locked(clock.unix_timestamp as u64),
+            PnsError::DomainLocked
+        );
 
         domain.owner = new_owner;
 
@@ -138,7 +157,10 @@ pub mod pns_anchor {
         Ok(())
     }
 
-    /// Mirrors Polygon state into a deterministic Domain PDA.
+    /// Mirrors Polygon state into a deterministic Domain PDA. Clears any
+    /// lockup the previous delegate held whenever the Solana delegate
+    /// actually changes, so it can't carry over and block the new owner's
+    /// `transfer_domain`/`update_delegate` calls.
     pub fn mirror_domain(
         ctx: Context<MirrorDomain>,
         name_hash: [u8; 32],
@@ -158,9 +180,28 @@ pub mod pns_anchor {
         );
 
         let was_uninitialized = domain.owner == Pubkey::default();
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        if !was_uninitialized {
+            require!(registry.has_quorum(), PnsError::InsufficientSignoffs);
+            registry.consume_quorum();
+        }
+
+        if !was_uninitialized
+            && domain.state(registry.grace_period_seconds, now) == DomainState::Active
+            && expiration < domain.expiration
+            && registry.conflict_policy != ConflictPolicy::LatestWriteWins
+        {
+            return err!(PnsError::ExpirationShortenNotAllowed);
+        }
+
+        let new_owner = solana_delegate.unwrap_or(registry.authority);
+        if new_owner != domain.owner {
+            domain.lockup = None;
+        }
 
         domain.name_hash = name_hash;
-        domain.owner = solana_delegate.unwrap_or(registry.authority);
+        domain.owner = new_owner;
         domain.polygon_owner = polygon_owner;
         domain.resolver = resolver;
         domain.expiration = expiration;
@@ -189,13 +230,20 @@ pub mod pns_anchor {
         new_delegate: Pubkey,
     ) -> Result<()> {
         let domain = &mut ctx.accounts.domain_account;
-        let registry = &ctx.accounts.registry;
+        let registry = &mut ctx.accounts.registry;
+        let clock = Clock::get()?;
 
         require_keys_eq!(
             registry.authority,
             ctx.accounts.authority.key(),
             PnsError::Unauthorized
         );
+        require!(registry.has_quorum(), PnsError::InsufficientSignoffs);
+        registry.consume_quorum();
+        require!(
+            !domain.is_locked(clock.unix_timestamp as u64),
+            PnsError::DomainLocked
+        );
 
         domain.owner = new_delegate;
 
@@ -228,6 +276,12 @@ pub mod pns_anchor {
         );
         require!(data.len() <= MAX_RECORD_LENGTH, PnsError::RecordTooLarge);
 
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            domain.state(registry.grace_period_seconds, now) != DomainState::Expired,
+            PnsError::DomainExpired
+        );
+
         if registry.conflict_policy == ConflictPolicy::PolygonPriority
             && record.version > 0
             && source_chain == ChainSource::Solana
@@ -238,6 +292,20 @@ pub mod pns_anchor {
         let now_slot = Clock::get()?.slot;
         let was_empty = record.domain == Pubkey::default();
 
+        if !was_empty {
+            let new_space = RecordAccount::space(data.len());
+            let current_space = record.to_account_info().data_len();
+            if new_space != current_space {
+                resize_record_account(
+                    &record.to_account_info(),
+                    &ctx.accounts.authority.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    current_space,
+                    new_space,
+                )?;
+            }
+        }
+
         record.domain = domain.key();
         record.key_hash = key_hash;
         record.record_type = record_type;
@@ -294,7 +362,7 @@ pub mod pns_anchor {
         nft_mint: Option<Pubkey>,
         wrap_state: WrapState,
     ) -> Result<()> {
-        let registry = &ctx.accounts.registry;
+        let registry = &mut ctx.accounts.registry;
         let domain = &mut ctx.accounts.domain_account;
 
         require_keys_eq!(
@@ -302,6 +370,8 @@ pub mod pns_anchor {
             ctx.accounts.authority.key(),
             PnsError::Unauthorized
         );
+        require!(registry.has_quorum(), PnsError::InsufficientSignoffs);
+        registry.consume_quorum();
 
         domain.nft_mint = nft_mint;
         domain.wrap_state = wrap_state;
@@ -314,6 +384,183 @@ pub mod pns_anchor {
 
         Ok(())
     }
+
+    /// Reclaims a domain whose `expiration + grace_period_seconds` has fully
+    /// elapsed, handing it to whoever signs the reclamation. Rejects during
+    /// the active window and the grace window that follows it.
+    pub fn reclaim_domain(
+        ctx: Context<ReclaimDomain>,
+        name_hash: [u8; 32],
+        duration: u64,
+        resolver: Option<Pubkey>,
+    ) -> Result<()> {
+        let domain = &mut ctx.accounts.domain_account;
+        let registry = &ctx.accounts.registry;
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        require!(duration > 0, PnsError::InvalidDuration);
+        require!(duration <= TEN_YEARS_IN_SECONDS, PnsError::InvalidDuration);
+        require!(
+            domain.state(registry.grace_period_seconds, now) == DomainState::Expired,
+            PnsError::DomainNotReclaimable
+        );
+
+        domain.name_hash = name_hash;
+        domain.owner = ctx.accounts.new_owner.key();
+        domain.resolver = resolver;
+        domain.expiration = now.saturating_add(duration);
+        domain.polygon_owner = [0u8; 20];
+        domain.last_polygon_tx = [0u8; 32];
+        domain.nft_mint = None;
+        domain.wrap_state = WrapState::None;
+        domain.record_count = 0;
+        domain.lockup = None;
+
+        msg!(
+            "Domain reclaimed by {}: expires={}",
+            domain.owner,
+            domain.expiration
+        );
+        Ok(())
+    }
+
+    /// Locks a domain for `duration` seconds to earn a decaying (or cliff)
+    /// bonus voting weight. Reuses the voter-stake-registry lockup model;
+    /// while locked, `transfer_domain`/`update_delegate` are rejected. An
+    /// already-active lockup can only be extended, never shortened, so the
+    /// owner cannot shrink it back to near-zero to regain transfer rights.
+    pub fn lock_domain(
+        ctx: Context<LockDomain>,
+        _name_hash: [u8; 32],
+        duration: u64,
+        kind: LockupKind,
+    ) -> Result<()> {
+        let domain = &mut ctx.accounts.domain_account;
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        require!(
+            domain.owner == ctx.accounts.owner.key(),
+            PnsError::Unauthorized
+        );
+        require!(duration > 0, PnsError::InvalidDuration);
+        require!(duration <= TEN_YEARS_IN_SECONDS, PnsError::InvalidDuration);
+
+        let new_end_ts = now.saturating_add(duration);
+        if let Some(existing) = domain.lockup {
+            if existing.is_active(now) {
+                require!(new_end_ts > existing.end_ts, PnsError::LockupShortenNotAllowed);
+            }
+        }
+
+        domain.lockup = Some(LockupEntry {
+            start_ts: now,
+            end_ts: new_end_ts,
+            kind,
+        });
+
+        msg!(
+            "Domain locked ({:?}) until {}",
+            kind,
+            new_end_ts
+        );
+        Ok(())
+    }
+
+    /// Recomputes a voter's governance weight from the `DomainAccount`s they
+    /// own, following the voter-stake-registry / spl-governance
+    /// `VoterWeightRecord` pattern so an external governance program can
+    /// consume the result via its own `voter_weight_record` account.
+    /// Each non-expired domain passed in `remaining_accounts` and owned by
+    /// the caller contributes `DOMAIN_WEIGHT_UNIT` of weight, plus the bonus
+    /// of its active lockup (if any). Duplicate accounts are rejected so the
+    /// same domain can't be counted twice.
+    pub fn update_voter_weight_record<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateVoterWeightRecord<'info>>,
+    ) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let owner = ctx.accounts.owner.key();
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        let mut voter_weight: u64 = 0;
+        let mut seen_domains = std::collections::BTreeSet::new();
+        for domain_info in ctx.remaining_accounts.iter() {
+            require!(
+                seen_domains.insert(domain_info.key()),
+                PnsError::DuplicateDomainAccount
+            );
+            let domain = Account::<DomainAccount>::try_from(domain_info)?;
+            require_keys_eq!(domain.owner, owner, PnsError::Unauthorized);
+            if domain.state(registry.grace_period_seconds, now) != DomainState::Expired {
+                voter_weight =
+                    voter_weight.saturating_add(domain.lockup_weight(DOMAIN_WEIGHT_UNIT, now));
+            }
+        }
+
+        let record = &mut ctx.accounts.voter_weight_record;
+        record.realm = registry.realm;
+        record.governing_token_mint = registry.governing_token_mint;
+        record.governing_token_owner = owner;
+        record.voter_weight = voter_weight;
+        record.voter_weight_expiry = Some(Clock::get()?.slot);
+        record.bump = ctx.bumps.voter_weight_record;
+
+        msg!(
+            "Voter weight record updated for {}: weight={}",
+            owner,
+            voter_weight
+        );
+        Ok(())
+    }
+
+    /// Registers a signatory allowed to co-sign privileged authority
+    /// operations, modeled on spl-governance's `SignatoryRecordV2`.
+    pub fn add_signatory(ctx: Context<AddSignatory>, signatory: Pubkey) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.registry.authority,
+            ctx.accounts.authority.key(),
+            PnsError::Unauthorized
+        );
+
+        let record = &mut ctx.accounts.signatory_record;
+        record.registry = ctx.accounts.registry.key();
+        record.signatory = signatory;
+        record.signed_off_generation = 0;
+        record.bump = ctx.bumps.signatory_record;
+
+        msg!("Signatory {} added", signatory);
+        Ok(())
+    }
+
+    /// Records a signatory's sign-off for the registry's *current*
+    /// `quorum_generation`. Once `signed_off_count` reaches
+    /// `required_signoffs`, the privileged authority instructions unlock for
+    /// that generation only - the gated instruction consumes the quorum and
+    /// advances the generation, so signatories must sign off again to
+    /// re-authorize the next privileged call. Idempotent within a
+    /// generation: signing off twice does not double-count.
+    pub fn sign_off(ctx: Context<SignOff>) -> Result<()> {
+        let record = &mut ctx.accounts.signatory_record;
+        let registry = &mut ctx.accounts.registry;
+
+        require_keys_eq!(
+            record.signatory,
+            ctx.accounts.signatory.key(),
+            PnsError::Unauthorized
+        );
+
+        if record.signed_off_generation != registry.quorum_generation {
+            record.signed_off_generation = registry.quorum_generation;
+            registry.signed_off_count = registry.signed_off_count.saturating_add(1);
+        }
+
+        emit!(SignOffRecorded {
+            registry: registry.key(),
+            signatory: record.signatory,
+            signed_off_count: registry.signed_off_count,
+        });
+
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -324,6 +571,13 @@ const TEN_YEARS_IN_SECONDS: u64 = 10 * 365 * 24 * 60 * 60;
 const MAX_RECORD_LENGTH: usize = 512;
 const REGISTRY_VERSION: u8 = 2;
 
+/// Fixed-point precision for one domain's base voting weight. `LockupEntry`
+/// weight computation floor-divides `base * remaining_secs /
+/// total_lockup_secs`, so `base` must carry enough precision that a
+/// `Decay` lockup doesn't round down to zero for nearly its entire
+/// duration.
+const DOMAIN_WEIGHT_UNIT: u64 = 1_000_000;
+
 // ============================================================================
 // ACCOUNTS
 // ============================================================================
@@ -334,12 +588,35 @@ pub struct Registry {
     pub polygon_registry: [u8; 20],      // 20
     pub domain_count: u64,               // 8
     pub conflict_policy: ConflictPolicy, // 1
+    pub grace_period_seconds: u64,       // 8
+    pub realm: Pubkey,                   // 32
+    pub governing_token_mint: Pubkey,    // 32
+    pub required_signoffs: u8,           // 1
+    pub signed_off_count: u8,            // 1
+    pub quorum_generation: u64,          // 8
     pub bump: u8,                        // 1
     pub version: u8,                     // 1
 }
 
 impl Registry {
-    pub const SPACE: usize = 8 + 32 + 20 + 8 + 1 + 1 + 1;
+    pub const SPACE: usize = 8 + 32 + 20 + 8 + 1 + 8 + 32 + 32 + 1 + 1 + 8 + 1 + 1;
+
+    /// Whether enough signatories have signed off, for the *current*
+    /// `quorum_generation`, to unlock the privileged authority instructions.
+    /// `required_signoffs == 0` always has quorum, preserving single-signer
+    /// behavior for registries that opt out.
+    pub fn has_quorum(&self) -> bool {
+        self.signed_off_count >= self.required_signoffs
+    }
+
+    /// Consumes the current quorum after a gated instruction executes and
+    /// advances to the next generation, so stale sign-offs can't unlock a
+    /// later, unrelated privileged call. Signatories must `sign_off` again
+    /// for the new generation to re-authorize future privileged calls.
+    pub fn consume_quorum(&mut self) {
+        self.signed_off_count = 0;
+        self.quorum_generation = self.quorum_generation.saturating_add(1);
+    }
 }
 
 #[account]
@@ -353,11 +630,43 @@ pub struct DomainAccount {
     pub nft_mint: Option<Pubkey>,  // 33
     pub wrap_state: WrapState,     // 1
     pub record_count: u16,         // 2
+    pub lockup: Option<LockupEntry>, // 18
     pub bump: u8,                  // 1
 }
 
 impl DomainAccount {
-    pub const SPACE: usize = 8 + 32 + 32 + 33 + 8 + 20 + 32 + 33 + 1 + 2 + 1;
+    pub const SPACE: usize = 8 + 32 + 32 + 33 + 8 + 20 + 32 + 33 + 1 + 2 + 18 + 1;
+
+    /// Derives the domain's lifecycle state from its expiration and the
+    /// registry's grace period, modeled on Alfis's expired-domain handling:
+    /// `Active` while unexpired, `Grace` for `grace_period_seconds` after
+    /// expiration, then `Expired` once the grace window has fully elapsed.
+    pub fn state(&self, grace_period_seconds: u64, now: u64) -> DomainState {
+        if now < self.expiration {
+            DomainState::Active
+        } else if now < self.expiration.saturating_add(grace_period_seconds) {
+            DomainState::Grace
+        } else {
+            DomainState::Expired
+        }
+    }
+
+    /// Whether a lockup is currently in effect for this domain.
+    pub fn is_locked(&self, now: u64) -> bool {
+        self.lockup.map_or(false, |lockup| lockup.is_active(now))
+    }
+
+    /// Effective voting weight for `base` units at `now`: `base` plus the
+    /// lockup's bonus weight (if any), which is zero once the lockup has
+    /// lapsed, so unlocked domains contribute `base` unchanged. Pass a
+    /// sufficiently precise `base` (e.g. `DOMAIN_WEIGHT_UNIT`) so `Decay`'s
+    /// floor division doesn't collapse to zero for most of the lockup.
+    pub fn lockup_weight(&self, base: u64, now: u64) -> u64 {
+        match self.lockup {
+            Some(lockup) => base.saturating_add(lockup.weight(base, now)),
+            None => base,
+        }
+    }
 }
 
 #[account]
@@ -380,6 +689,73 @@ impl RecordAccount {
     }
 }
 
+/// Right-sizes `record_account` to `new_space`, zero-initializing any newly
+/// allocated bytes on growth, and settles the rent delta against `authority`
+/// (collecting more lamports when growing, refunding the surplus when
+/// shrinking). Mirrors the account-resizing flow used by the System
+/// program's own `realloc` support.
+fn resize_record_account<'info>(
+    record_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    current_space: usize,
+    new_space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let new_minimum_balance = rent.minimum_balance(new_space);
+    let current_balance = record_account.lamports();
+
+    if new_minimum_balance > current_balance {
+        let lamports_diff = new_minimum_balance - current_balance;
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: authority.clone(),
+                    to: record_account.clone(),
+                },
+            ),
+            lamports_diff,
+        )?;
+    } else if current_balance > new_minimum_balance {
+        let lamports_diff = current_balance - new_minimum_balance;
+        **record_account.try_borrow_mut_lamports()? -= lamports_diff;
+        **authority.try_borrow_mut_lamports()? += lamports_diff;
+    }
+
+    record_account.realloc(new_space, new_space > current_space)?;
+
+    Ok(())
+}
+
+#[account]
+pub struct VoterWeightRecord {
+    pub realm: Pubkey,                    // 32
+    pub governing_token_mint: Pubkey,     // 32
+    pub governing_token_owner: Pubkey,    // 32
+    pub voter_weight: u64,                // 8
+    pub voter_weight_expiry: Option<u64>, // 9
+    pub bump: u8,                         // 1
+}
+
+impl VoterWeightRecord {
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 8 + 9 + 1;
+}
+
+#[account]
+pub struct SignatoryRecord {
+    pub registry: Pubkey,             // 32
+    pub signatory: Pubkey,            // 32
+    /// The `Registry::quorum_generation` this signatory last signed off on.
+    /// `0` means never signed, since generations start at `1`.
+    pub signed_off_generation: u64,   // 8
+    pub bump: u8,                     // 1
+}
+
+impl SignatoryRecord {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 1;
+}
+
 // ============================================================================
 // CONTEXTS
 // ============================================================================
@@ -472,7 +848,7 @@ pub struct MirrorDomain<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(name_hash: [u8; 32], key_hash: [u8; 32])]
+#[instruction(name_hash: [u8; 32], key_hash: [u8; 32], record_type: RecordType, data: Vec<u8>)]
 pub struct UpsertRecord<'info> {
     #[account(
         mut,
@@ -483,7 +859,7 @@ pub struct UpsertRecord<'info> {
     #[account(
         init_if_needed,
         payer = authority,
-        space = RecordAccount::space(MAX_RECORD_LENGTH),
+        space = RecordAccount::space(data.len()),
         seeds = [b"record", domain_account.key().as_ref(), key_hash.as_ref()],
         bump
     )]
@@ -526,7 +902,7 @@ pub struct UpdateDelegate<'info> {
         bump = domain_account.bump
     )]
     pub domain_account: Account<'info, DomainAccount>,
-    #[account(seeds = [b"registry"], bump = registry.bump)]
+    #[account(mut, seeds = [b"registry"], bump = registry.bump)]
     pub registry: Account<'info, Registry>,
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -541,10 +917,84 @@ pub struct SetWrapState<'info> {
         bump = domain_account.bump
     )]
     pub domain_account: Account<'info, DomainAccount>,
+    #[account(mut, seeds = [b"registry"], bump = registry.bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name_hash: [u8; 32])]
+pub struct ReclaimDomain<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain", name_hash.as_ref()],
+        bump = domain_account.bump
+    )]
+    pub domain_account: Account<'info, DomainAccount>,
+    #[account(seeds = [b"registry"], bump = registry.bump)]
+    pub registry: Account<'info, Registry>,
+    pub new_owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name_hash: [u8; 32])]
+pub struct LockDomain<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain", name_hash.as_ref()],
+        bump = domain_account.bump
+    )]
+    pub domain_account: Account<'info, DomainAccount>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = VoterWeightRecord::SPACE,
+        seeds = [b"voter-weight", owner.key().as_ref()],
+        bump
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+    #[account(seeds = [b"registry"], bump = registry.bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(signatory: Pubkey)]
+pub struct AddSignatory<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SignatoryRecord::SPACE,
+        seeds = [b"signatory", registry.key().as_ref(), signatory.as_ref()],
+        bump
+    )]
+    pub signatory_record: Account<'info, SignatoryRecord>,
     #[account(seeds = [b"registry"], bump = registry.bump)]
     pub registry: Account<'info, Registry>,
     #[account(mut)]
     pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SignOff<'info> {
+    #[account(
+        mut,
+        seeds = [b"signatory", registry.key().as_ref(), signatory.key().as_ref()],
+        bump = signatory_record.bump
+    )]
+    pub signatory_record: Account<'info, SignatoryRecord>,
+    #[account(mut, seeds = [b"registry"], bump = registry.bump)]
+    pub registry: Account<'info, Registry>,
+    pub signatory: Signer<'info>,
 }
 
 // ============================================================================
@@ -586,6 +1036,69 @@ pub enum ConflictPolicy {
     LatestWriteWins = 1,
 }
 
+/// Deterministic domain lifecycle derived from `expiration` and the
+/// registry's `grace_period_seconds`.
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DomainState {
+    Active = 0,
+    Grace = 1,
+    Expired = 2,
+}
+
+/// Decay behavior for a `LockupEntry`, mirroring voter-stake-registry's
+/// lockup kinds.
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LockupKind {
+    /// Weight ramps down linearly from `base` to zero over the lockup.
+    #[default]
+    Decay = 0,
+    /// Weight stays at `base` until `end_ts`, then drops to zero.
+    Cliff = 1,
+}
+
+/// A time-bound lockup on a `DomainAccount`, capped at
+/// `TEN_YEARS_IN_SECONDS` by `lock_domain`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LockupEntry {
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub kind: LockupKind,
+}
+
+impl LockupEntry {
+    /// Whether `now` still falls within the lockup window.
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.end_ts
+    }
+
+    /// Effective weight for `base` units at `now`.
+    ///
+    /// `Decay` ramps down linearly: `base * remaining_secs /
+    /// total_lockup_secs`, using floor (integer) division, so the result is
+    /// already zero once `base * remaining_secs < total_lockup_secs`; for a
+    /// small `base` that covers most of the lockup.
+    /// `Cliff` holds `base` until `end_ts`. Both variants yield exactly zero
+    /// once `now >= end_ts`.
+    pub fn weight(&self, base: u64, now: u64) -> u64 {
+        if now >= self.end_ts {
+            return 0;
+        }
+        match self.kind {
+            LockupKind::Cliff => base,
+            LockupKind::Decay => {
+                let remaining_secs = self.end_ts - now;
+                let total_lockup_secs = self.end_ts.saturating_sub(self.start_ts);
+                if total_lockup_secs == 0 {
+                    return 0;
+                }
+                (base as u128 * remaining_secs as u128 / total_lockup_secs as u128) as u64
+            }
+        }
+    }
+}
+
 // ============================================================================
 // EVENTS
 // ============================================================================
@@ -626,6 +1139,13 @@ pub struct DelegateUpdated {
     pub delegate: Pubkey,
 }
 
+#[event]
+pub struct SignOffRecorded {
+    pub registry: Pubkey,
+    pub signatory: Pubkey,
+    pub signed_off_count: u8,
+}
+
 // ============================================================================
 // ERRORS
 // ============================================================================
@@ -646,4 +1166,16 @@ pub enum PnsError {
     RecordTooLarge,
     #[msg("Conflict policy prevented the write")]
     ConflictViolation,
+    #[msg("Mirrored expiration would shorten a still-valid domain")]
+    ExpirationShortenNotAllowed,
+    #[msg("Domain is not yet reclaimable - still active or within its grace period")]
+    DomainNotReclaimable,
+    #[msg("Domain is locked and cannot change owner until the lockup lapses")]
+    DomainLocked,
+    #[msg("Not enough signatories have signed off on this privileged operation")]
+    InsufficientSignoffs,
+    #[msg("Cannot shorten an active lockup - extend it or wait for it to lapse")]
+    LockupShortenNotAllowed,
+    #[msg("The same domain account was passed more than once")]
+    DuplicateDomainAccount,
 }